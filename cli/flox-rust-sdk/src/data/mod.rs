@@ -1,9 +1,11 @@
 mod canonical_path;
+mod system;
 mod version;
 
 use std::fmt::Display;
 
 pub use canonical_path::{CanonicalPath, CanonicalizeError};
+pub use system::SystemKind;
 pub use version::Version;
 pub type System = String;
 
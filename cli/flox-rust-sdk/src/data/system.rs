@@ -0,0 +1,118 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::System;
+
+/// A [System] parsed into one of the systems supported by flox
+///
+/// Systems that aren't known are kept as [SystemKind::Other],
+/// so parsing never fails and no information is lost.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum SystemKind {
+    Aarch64Darwin,
+    Aarch64Linux,
+    X8664Darwin,
+    X8664Linux,
+    Other(String),
+}
+
+impl SystemKind {
+    /// All systems known to flox
+    pub const KNOWN: [SystemKind; 4] = [
+        SystemKind::Aarch64Darwin,
+        SystemKind::Aarch64Linux,
+        SystemKind::X8664Darwin,
+        SystemKind::X8664Linux,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            SystemKind::Aarch64Darwin => "aarch64-darwin",
+            SystemKind::Aarch64Linux => "aarch64-linux",
+            SystemKind::X8664Darwin => "x86_64-darwin",
+            SystemKind::X8664Linux => "x86_64-linux",
+            SystemKind::Other(system) => system,
+        }
+    }
+
+    /// Whether this is one of the systems in [SystemKind::KNOWN]
+    pub fn is_known(&self) -> bool {
+        !matches!(self, SystemKind::Other(_))
+    }
+}
+
+impl FromStr for SystemKind {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "aarch64-darwin" => SystemKind::Aarch64Darwin,
+            "aarch64-linux" => SystemKind::Aarch64Linux,
+            "x86_64-darwin" => SystemKind::X8664Darwin,
+            "x86_64-linux" => SystemKind::X8664Linux,
+            other => SystemKind::Other(other.to_string()),
+        })
+    }
+}
+
+impl From<&str> for SystemKind {
+    fn from(value: &str) -> Self {
+        let Ok(system) = value.parse();
+        system
+    }
+}
+
+impl From<System> for SystemKind {
+    fn from(value: System) -> Self {
+        match value.parse() {
+            Ok(SystemKind::Other(_)) => SystemKind::Other(value),
+            Ok(system) => system,
+        }
+    }
+}
+
+impl From<SystemKind> for System {
+    fn from(value: SystemKind) -> Self {
+        match value {
+            SystemKind::Other(system) => system,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl Display for SystemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_systems_round_trip() {
+        for system in SystemKind::KNOWN {
+            assert!(system.is_known());
+            assert_eq!(SystemKind::from(system.to_string()), system);
+        }
+    }
+
+    #[test]
+    fn unknown_system_is_preserved() {
+        let system = SystemKind::from("riscv64-linux");
+        assert_eq!(system, SystemKind::Other("riscv64-linux".to_string()));
+        assert_eq!(System::from(system), "riscv64-linux");
+    }
+
+    #[test]
+    fn serializes_as_string() {
+        let system: SystemKind = serde_json::from_str(r#""x86_64-linux""#).unwrap();
+        assert_eq!(system, SystemKind::X8664Linux);
+        assert_eq!(serde_json::to_string(&system).unwrap(), r#""x86_64-linux""#);
+    }
+}
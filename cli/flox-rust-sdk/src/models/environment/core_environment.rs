@@ -1289,6 +1289,8 @@ mod tests {
                 url: "url".to_string(),
                 complete: true,
             }),
+            closure: vec![],
+            messages: vec![],
         }]);
 
         let (_, upgraded_packages) = env_view
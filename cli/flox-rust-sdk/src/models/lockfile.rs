@@ -416,6 +416,8 @@ impl LockedManifestCatalog {
                     .or_insert_with(|| PackageGroup {
                         descriptors: Vec::new(),
                         name: group_name.to_string(),
+                        stability: None,
+                        version_constraints: Default::default(),
                     });
 
             let systems = manifest_descriptor
@@ -1016,6 +1018,8 @@ pub(crate) mod tests {
                 allowed_licenses: None,
                 systems: vec![SystemEnum::Aarch64Darwin],
             }],
+            stability: None,
+            version_constraints: Default::default(),
         }]
     });
 
@@ -1055,6 +1059,8 @@ pub(crate) mod tests {
                 }]),
             }),
             name: "group".to_string(),
+            closure: vec![],
+            messages: vec![],
         }]
     });
 
@@ -1216,6 +1222,8 @@ pub(crate) mod tests {
                     systems: vec![SystemEnum::X8664Linux],
                 },
             ],
+            stability: None,
+            version_constraints: Default::default(),
         }];
 
         let actual_params = LockedManifestCatalog::collect_package_groups(&manifest, None)
@@ -1280,6 +1288,8 @@ pub(crate) mod tests {
                     systems: vec![SystemEnum::X8664Linux],
                 },
             ],
+            stability: None,
+            version_constraints: Default::default(),
         }];
 
         let actual_params = LockedManifestCatalog::collect_package_groups(&manifest, None)
@@ -1332,6 +1342,8 @@ pub(crate) mod tests {
                     systems: vec![SystemEnum::X8664Linux],
                 },
             ],
+            stability: None,
+            version_constraints: Default::default(),
         }];
 
         let actual_params = LockedManifestCatalog::collect_package_groups(&manifest, None)
@@ -1375,6 +1387,8 @@ pub(crate) mod tests {
                     allowed_licenses: None,
                     systems: vec![SystemEnum::Aarch64Darwin],
                 }],
+                stability: None,
+                version_constraints: Default::default(),
             },
             PackageGroup {
                 name: "group2".to_string(),
@@ -1389,6 +1403,8 @@ pub(crate) mod tests {
                     allowed_licenses: None,
                     systems: vec![SystemEnum::Aarch64Darwin],
                 }],
+                stability: None,
+                version_constraints: Default::default(),
             },
         ];
 
@@ -1452,6 +1468,8 @@ pub(crate) mod tests {
                     systems: vec![SystemEnum::Aarch64Darwin],
                 },
             ],
+            stability: None,
+            version_constraints: Default::default(),
         }];
 
         assert_eq!(actual_params, expected_params);
@@ -1602,6 +1620,8 @@ pub(crate) mod tests {
                 }]),
             }),
             name: "group".to_string(),
+            closure: vec![],
+            messages: vec![],
         }];
 
         let manifest = &*TEST_TYPED_MANIFEST;
@@ -1806,6 +1826,8 @@ pub(crate) mod tests {
                     systems: vec![SystemEnum::Aarch64Darwin,],
                 }
             ],
+            stability: None,
+            version_constraints: Default::default(),
         }]);
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::io::{BufRead, BufReader};
 use std::num::NonZeroU8;
 use std::path::PathBuf;
@@ -11,6 +12,7 @@ use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use super::pkgdb::PkgDbError;
+use crate::data::SystemKind;
 use crate::models::pkgdb::PKGDB_BIN;
 use crate::utils::CommandExt;
 
@@ -252,11 +254,57 @@ pub enum Subtree {
 /// without an enclosing `[]`, so the results returned by `pkgdb` can't be
 /// directly deserialized to a JSON object. To parse the results you should
 /// use the provided `TryFrom` impl.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SearchResults {
     pub results: Vec<SearchResult>,
     pub count: ResultCount,
 }
+
+impl SearchResults {
+    /// Drop results whose license is not one of `allowed`.
+    ///
+    /// Results without a license are kept if `include_unlicensed` is true.
+    /// `count` is left unchanged,
+    /// since it counts all matches including those that weren't fetched.
+    pub fn filter_by_license(&mut self, allowed: &[impl AsRef<str>], include_unlicensed: bool) {
+        self.results.retain(|result| match &result.license {
+            Some(license) => allowed.iter().any(|allowed| allowed.as_ref() == license),
+            None => include_unlicensed,
+        });
+    }
+
+    /// Drop results that are known to be broken.
+    ///
+    /// Results without a `broken` flag are kept.
+    /// `count` is left unchanged, as for [Self::filter_by_license].
+    pub fn exclude_broken(&mut self) {
+        self.results.retain(|result| result.broken != Some(true));
+    }
+
+    /// Drop results that are known to be insecure.
+    ///
+    /// Results without an `insecure` flag are kept.
+    /// `count` is left unchanged, as for [Self::filter_by_license].
+    pub fn exclude_insecure(&mut self) {
+        self.results.retain(|result| result.insecure != Some(true));
+    }
+
+    /// Remove results for the same attribute path, version, and system,
+    /// keeping the first occurrence.
+    ///
+    /// Results are compared by `rel_path` rather than `pname`,
+    /// since different attribute paths can provide packages of the same name.
+    pub fn dedup(&mut self) {
+        let mut seen = HashSet::new();
+        self.results.retain(|result| {
+            seen.insert((
+                result.rel_path.clone(),
+                result.version.clone(),
+                result.system.clone(),
+            ))
+        });
+    }
+}
 pub type ResultCount = Option<u64>;
 
 /// The types of JSON records that `pkgdb` can emit on stdout during a search
@@ -452,6 +500,30 @@ pub struct SearchResult {
     pub description: Option<String>,
     /// Which license the package is licensed under
     pub license: Option<String>,
+    /// Whether nixpkgs marks the package as broken (`meta.broken`)
+    ///
+    /// This and the following flags are not provided by the catalog's search API,
+    /// so results from the catalog and from pkgdb leave them as `None`.
+    /// They are only set on results deserialized with them,
+    /// e.g. the fixtures of a [crate::providers::catalog::FileClient].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broken: Option<bool>,
+    /// Whether nixpkgs marks the package as insecure (`meta.insecure`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure: Option<bool>,
+    /// Whether the package is unsupported on [Self::system] (`meta.unsupported`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsupported: Option<bool>,
+    /// Whether the package has an unfree license (`meta.unfree`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfree: Option<bool>,
+}
+
+impl SearchResult {
+    /// The system of the package parsed into a [SystemKind]
+    pub fn system_kind(&self) -> SystemKind {
+        SystemKind::from(self.system.as_str())
+    }
 }
 
 #[cfg(test)]
@@ -527,6 +599,143 @@ mod test {
         assert!(search_results.results.len() == 1);
     }
 
+    fn result_with_license(name: &str, license: Option<&str>) -> SearchResult {
+        SearchResult {
+            rel_path: vec![name.to_string()],
+            license: license.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn filters_by_license() {
+        let mut search_results = SearchResults {
+            results: vec![
+                result_with_license("hello", Some("GPL-3.0-or-later")),
+                result_with_license("ripgrep", Some("MIT")),
+                result_with_license("unknown", None),
+            ],
+            count: Some(3),
+        };
+
+        let mut without_unlicensed = search_results.clone();
+        without_unlicensed.filter_by_license(&["MIT", "Apache-2.0"], false);
+        assert_eq!(without_unlicensed.results, [result_with_license(
+            "ripgrep",
+            Some("MIT")
+        )]);
+        assert_eq!(without_unlicensed.count, Some(3));
+
+        search_results.filter_by_license(&["MIT", "Apache-2.0"], true);
+        assert_eq!(search_results.results, [
+            result_with_license("ripgrep", Some("MIT")),
+            result_with_license("unknown", None)
+        ]);
+    }
+
+    #[test]
+    fn excludes_broken_and_insecure_results() {
+        let flagged = |name: &str, broken: Option<bool>, insecure: Option<bool>| SearchResult {
+            rel_path: vec![name.to_string()],
+            broken,
+            insecure,
+            ..Default::default()
+        };
+        let mut search_results = SearchResults {
+            results: vec![
+                flagged("broken", Some(true), None),
+                flagged("insecure", Some(false), Some(true)),
+                flagged("fine", Some(false), Some(false)),
+                flagged("unknown", None, None),
+            ],
+            count: Some(4),
+        };
+
+        let mut without_broken = search_results.clone();
+        without_broken.exclude_broken();
+        assert_eq!(without_broken.results, search_results.results[1..]);
+        assert_eq!(without_broken.count, Some(4));
+
+        search_results.exclude_insecure();
+        assert_eq!(search_results.results, [
+            flagged("broken", Some(true), None),
+            flagged("fine", Some(false), Some(false)),
+            flagged("unknown", None, None),
+        ]);
+    }
+
+    fn result_with_version(rel_path: &[&str], version: &str) -> SearchResult {
+        SearchResult {
+            rel_path: rel_path.iter().map(|s| s.to_string()).collect(),
+            pname: rel_path.last().map(|s| s.to_string()),
+            version: Some(version.to_string()),
+            system: "x86_64-linux".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn dedup_keeps_first_of_identical_results() {
+        let mut search_results = SearchResults {
+            results: vec![
+                result_with_version(&["hello"], "2.12.1"),
+                result_with_version(&["python3Packages", "hello"], "2.12.1"),
+                result_with_version(&["hello"], "2.12.1"),
+            ],
+            count: Some(3),
+        };
+        search_results.dedup();
+        assert_eq!(search_results.results, [
+            result_with_version(&["hello"], "2.12.1"),
+            result_with_version(&["python3Packages", "hello"], "2.12.1"),
+        ]);
+    }
+
+    #[test]
+    fn dedup_keeps_results_differing_only_in_version() {
+        let results = vec![
+            result_with_version(&["hello"], "2.12.1"),
+            result_with_version(&["hello"], "2.12"),
+        ];
+        let mut search_results = SearchResults {
+            results: results.clone(),
+            count: Some(2),
+        };
+        search_results.dedup();
+        assert_eq!(search_results.results, results);
+    }
+
+    #[test]
+    fn search_results_round_trip_through_json() {
+        let search_results = SearchResults {
+            results: vec![
+                SearchResult {
+                    input: "nixpkgs".to_string(),
+                    system: "aarch64-darwin".to_string(),
+                    rel_path: vec!["python310Packages".to_string(), "flask".to_string()],
+                    pname: Some("flask".to_string()),
+                    version: Some("3.0.0".to_string()),
+                    description: Some("A microframework".to_string()),
+                    license: Some("BSD-3-Clause".to_string()),
+                    broken: Some(false),
+                    insecure: Some(false),
+                    unsupported: None,
+                    unfree: Some(false),
+                },
+                result_with_license("unknown", None),
+            ],
+            count: None,
+        };
+
+        let json = serde_json::to_value(&search_results).unwrap();
+        assert_eq!(
+            json["results"][0]["relPath"],
+            serde_json::json!(["python310Packages", "flask"])
+        );
+        let deserialized: SearchResults = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, search_results);
+    }
+
     #[test]
     fn deserializes_result_count() {
         let count: Record = serde_json::from_str(EXAMPLE_RESULT_COUNT).unwrap();
@@ -563,6 +563,8 @@ async fn get_default_package_if_compatible(
                     systems: vec![flox.system.parse()?],
                 }],
                 name: "default".to_string(),
+                stability: None,
+                version_constraints: Default::default(),
             }])
             .await?;
         let pkg: Option<ProvidedPackage> = resolved_groups
@@ -631,6 +633,8 @@ async fn get_default_package(flox: &Flox, package: &AttrPath) -> Result<Provided
                     systems: vec![flox.system.parse()?],
                 }],
                 name: package.to_string(),
+                stability: None,
+                version_constraints: Default::default(),
             }])
             .await?;
         let pkg: Option<ProvidedPackage> = resolved_groups
@@ -707,6 +711,8 @@ async fn try_find_compatible_version(
                     systems: vec![flox.system.parse()?],
                 }],
                 name: pname.to_string(),
+                stability: None,
+                version_constraints: Default::default(),
             }])
             .await?;
         let pkg: Option<ProvidedPackage> = resolved_groups
@@ -828,6 +834,8 @@ mod tests {
         ResolvedPackageGroup {
             name: group_name.to_string(),
             page: Some(page),
+            closure: vec![],
+            messages: vec![],
         }
     }
 
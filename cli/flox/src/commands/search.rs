@@ -1,5 +1,5 @@
 use std::fmt::Write;
-use std::num::NonZeroU8;
+use std::num::{NonZeroU32, NonZeroU8};
 use std::time::Duration;
 
 use anyhow::{bail, Context, Result};
@@ -7,7 +7,7 @@ use bpaf::Bpaf;
 use flox_rust_sdk::flox::Flox;
 use flox_rust_sdk::models::environment::global_manifest_path;
 use flox_rust_sdk::models::search::{do_search, PathOrJson, SearchResults};
-use flox_rust_sdk::providers::catalog::ClientTrait;
+use flox_rust_sdk::providers::catalog::{ClientTrait, SearchMode, NIXPKGS_CATALOG};
 use indoc::formatdoc;
 use log::debug;
 use tracing::instrument;
@@ -81,8 +81,10 @@ impl Search {
                 typed: Spinner::new(|| {
                     tokio::runtime::Handle::current().block_on(client.search(
                         &self.search_term,
+                        NIXPKGS_CATALOG,
                         flox.system.clone(),
-                        limit,
+                        limit.map(NonZeroU32::from),
+                        SearchMode::default(),
                     ))
                 }),
             }